@@ -0,0 +1,327 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::range_or_cell::{intersect_rect, rect_minus, rects_to_reference};
+use crate::{Address, Error, Index, RangeOrCell, Result};
+
+/// A fully-qualified reference: an optional sheet name plus a [`RangeOrCell`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct A1 {
+    pub sheet_name: Option<String>,
+    pub reference: RangeOrCell,
+}
+
+impl A1 {
+    /// Does this reference contain `other`?  The sheet name (if any) is ignored.
+    pub fn contains(&self, other: &Self) -> bool {
+        self.reference.contains(&other.reference)
+    }
+
+    /// Iterate over the pieces of this reference, in row-major order.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = RangeOrCell> + '_> {
+        self.reference.iter()
+    }
+
+    pub fn shift_right(&self, delta: Index) -> Self {
+        Self { sheet_name: self.sheet_name.clone(), reference: self.reference.shift_right(delta) }
+    }
+
+    pub fn shift_left(&self, delta: Index) -> Self {
+        Self { sheet_name: self.sheet_name.clone(), reference: self.reference.shift_left(delta) }
+    }
+
+    pub fn shift_down(&self, delta: Index) -> Self {
+        Self { sheet_name: self.sheet_name.clone(), reference: self.reference.shift_down(delta) }
+    }
+
+    pub fn shift_up(&self, delta: Index) -> Self {
+        Self { sheet_name: self.sheet_name.clone(), reference: self.reference.shift_up(delta) }
+    }
+
+    /// The overlap between this reference and `other`, or `None` if they don't overlap.  The
+    /// result is normalized: a single `RangeOrCell` when the overlap is one rectangle, otherwise
+    /// a `MultiArea`. The sheet name (if any) is taken from `self`.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let rects = self
+            .reference
+            .rects()
+            .iter()
+            .flat_map(|a| other.reference.rects().iter().filter_map(move |b| intersect_rect(a, b)).collect::<Vec<_>>())
+            .collect();
+
+        rects_to_reference(rects).map(|reference| Self { sheet_name: self.sheet_name.clone(), reference })
+    }
+
+    /// The combination of this reference and `other`, with overlapping and adjacent pieces merged
+    /// into maximal rectangles. Whole-column/whole-row pieces can still overlap other pieces (e.g.
+    /// `A:A` and `1:1` both stay, sharing `A1`). The sheet name (if any) is taken from `self`.
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        let mut rects = self.reference.rects();
+        rects.extend(other.reference.rects());
+
+        rects_to_reference(rects).map(|reference| Self { sheet_name: self.sheet_name.clone(), reference })
+    }
+
+    /// This reference with the overlap (if any) of `other` removed, or `None` if `other` fully
+    /// covers it. The sheet name (if any) is taken from `self`.
+    ///
+    /// Removing a bounded region from an open axis (a `ColumnRange`'s rows or a `RowRange`'s
+    /// columns) can leave a remainder this crate has no syntax for — e.g. "every row but 5" — in
+    /// which case the affected column/row is kept whole rather than dropped, so `None` always
+    /// means `other` truly covers `self`, never "this couldn't be computed exactly". Crop to a
+    /// concrete sheet size first ([`Self::crop`]) for an exact result in that case.
+    pub fn difference(&self, other: &Self) -> Option<Self> {
+        let mut remaining = self.reference.rects();
+
+        for b in other.reference.rects() {
+            remaining = remaining.iter().flat_map(|a| rect_minus(a, &b)).collect();
+        }
+
+        rects_to_reference(remaining).map(|reference| Self { sheet_name: self.sheet_name.clone(), reference })
+    }
+
+    /// Clamp this reference to a concrete sheet of `(width, height)` cells, bounding any open
+    /// `ColumnRange`/`RowRange` dimension, e.g. `A:A` on a 10x20 sheet crops to `A1:A20`. Returns
+    /// `None` if this reference falls entirely outside the sheet.
+    pub fn crop(&self, bounds: (Index, Index)) -> Option<Self> {
+        let (width, height) = bounds;
+
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        let sheet = Self {
+            sheet_name: None,
+            reference: RangeOrCell::Range { from: Address::new(0, 0), to: Address::new(width - 1, height - 1) },
+        };
+
+        self.intersect(&sheet)
+    }
+
+    /// Pair each cell address covered by this reference with the corresponding element of `data`,
+    /// a flat, row-major buffer of the given `width` (as produced by a `Grid`/worksheet range that
+    /// stores cells in a single `Vec`). An open `ColumnRange`/`RowRange` dimension is bounded to
+    /// the extent implied by `data`/`width`. Addresses that fall outside `width` or `data` are
+    /// skipped rather than panicking.
+    pub fn zip_with<'a, T>(&self, data: &'a [T], width: Index) -> impl Iterator<Item = (Address, &'a T)> + 'a {
+        let height = if width == 0 { 0 } else { data.len().div_ceil(width) };
+
+        self.reference.cells(width, height).filter_map(move |address| {
+            if address.column.x >= width {
+                return None;
+            }
+
+            data.get(address.row.y * width + address.column.x).map(|value| (address, value))
+        })
+    }
+
+    /// Render this reference in R1C1 notation (e.g. `R3C2`, or `R[1]C[-2]` relative to `anchor`).
+    pub fn to_r1c1(&self, anchor: Address) -> String {
+        let reference = self.reference.to_r1c1(anchor);
+
+        match &self.sheet_name {
+            Some(name) => format!("{name}!{reference}"),
+            None => reference,
+        }
+    }
+
+    /// Parse an R1C1-style reference (e.g. `"R3C2"` or `"R[1]C[-2]"`), resolving any relative
+    /// offsets against `anchor`.
+    pub fn from_r1c1(s: &str, anchor: Address) -> Result<Self> {
+        let (sheet_name, rest) = match s.split_once('!') {
+            Some((name, rest)) => (Some(name.to_string()), rest),
+            None => (None, s),
+        };
+
+        let mut pieces = rest
+            .split(',')
+            .map(|piece| RangeOrCell::from_r1c1(piece, anchor))
+            .collect::<Result<Vec<_>>>()?;
+
+        let reference = if pieces.len() == 1 { pieces.remove(0) } else { RangeOrCell::MultiArea(pieces) };
+
+        Ok(Self { sheet_name, reference })
+    }
+}
+
+impl fmt::Display for A1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = &self.sheet_name {
+            write!(f, "{name}!")?;
+        }
+        write!(f, "{}", self.reference)
+    }
+}
+
+impl FromStr for A1 {
+    type Err = Error;
+
+    /// Parse a full A1-style reference, e.g. `"Foo!A1:B5,D1:D5"`.  A comma-separated reference
+    /// outside of the (optional) `Sheet!` prefix becomes a [`RangeOrCell::MultiArea`].
+    fn from_str(s: &str) -> Result<Self> {
+        let (sheet_name, rest) = match s.split_once('!') {
+            Some((name, rest)) => (Some(name.to_string()), rest),
+            None => (None, s),
+        };
+
+        let mut pieces = rest
+            .split(',')
+            .map(RangeOrCell::from_str)
+            .collect::<Result<Vec<_>>>()?;
+
+        let reference = if pieces.len() == 1 { pieces.remove(0) } else { RangeOrCell::MultiArea(pieces) };
+
+        Ok(Self { sheet_name, reference })
+    }
+}
+
+impl<T: Into<RangeOrCell>> From<T> for A1 {
+    fn from(value: T) -> Self {
+        Self { sheet_name: None, reference: value.into() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersect_overlapping_ranges() {
+        let range = A1::from_str("B2:D10").unwrap();
+        let hole = A1::from_str("C5:C6").unwrap();
+
+        assert_eq!(&range.intersect(&hole).unwrap().to_string(), "C5:C6");
+    }
+
+    #[test]
+    fn intersect_disjoint_ranges_is_none() {
+        let a = A1::from_str("A1:B2").unwrap();
+        let b = A1::from_str("C3:D4").unwrap();
+
+        assert_eq!(a.intersect(&b), None);
+    }
+
+    #[test]
+    fn union_disjoint_ranges() {
+        let a = A1::from_str("A1:B2").unwrap();
+        let b = A1::from_str("C3:D4").unwrap();
+
+        assert_eq!(&a.union(&b).unwrap().to_string(), "A1:B2,C3:D4");
+    }
+
+    #[test]
+    fn union_merges_adjacent_columns() {
+        let a = A1::from_str("A:A,C:C").unwrap();
+        let b = A1::from_str("B:B").unwrap();
+
+        assert_eq!(&a.union(&b).unwrap().to_string(), "A:C");
+    }
+
+    #[test]
+    fn difference_leaves_an_l_shape() {
+        let range = A1::from_str("B2:D10").unwrap();
+        let hole = A1::from_str("C5:C6").unwrap();
+
+        assert_eq!(&range.difference(&hole).unwrap().to_string(), "B2:B10,C2:C4,C7:C10,D2:D10");
+    }
+
+    #[test]
+    fn difference_full_coverage_is_none() {
+        let range = A1::from_str("A1:B2").unwrap();
+
+        assert_eq!(range.difference(&range), None);
+    }
+
+    #[test]
+    fn difference_preserves_untouched_columns_of_an_open_range() {
+        let columns = A1::from_str("A:C").unwrap();
+        let hole = A1::from_str("B1:B2").unwrap();
+
+        // B1:B2 only touches column B, which itself can't be cut to an exact remainder (its rows
+        // beyond 1:2 are unbounded), so it comes back whole too; A and C, which B1:B2 never
+        // overlaps at all, come back whole either way. Both are now the same "kept as a crop-able
+        // approximation" rect, so they coalesce right back into the original "A:C".
+        assert_eq!(&columns.difference(&hole).unwrap().to_string(), "A:C");
+    }
+
+    #[test]
+    fn difference_keeps_an_open_column_whole_when_the_hole_spans_it_exactly() {
+        let column = A1::from_str("A:A").unwrap();
+        let hole = A1::from_str("A1").unwrap();
+
+        // A1 only removes row 1 from column A, but "every row except 1" has no syntax in this
+        // crate, so the whole column is kept as a crop-able approximation rather than the result
+        // coming back `None` (which would wrongly claim A1 fully covers A:A).
+        assert_eq!(&column.difference(&hole).unwrap().to_string(), "A:A");
+    }
+
+    #[test]
+    fn difference_keeps_an_open_row_whole_when_the_hole_spans_it_exactly() {
+        let row = A1::from_str("1:1").unwrap();
+        let hole = A1::from_str("A1").unwrap();
+
+        assert_eq!(&row.difference(&hole).unwrap().to_string(), "1:1");
+    }
+
+    #[test]
+    fn difference_keeps_the_overlapped_column_of_a_wider_open_range() {
+        let columns = A1::from_str("A:B").unwrap();
+        let hole = A1::from_str("A5").unwrap();
+
+        // A5 only cuts a bounded slice out of column A, whose rows are unbounded, so column A is
+        // kept whole (approximate) alongside the exact "B:B", and the two adjacent open columns
+        // coalesce back into "A:B".
+        assert_eq!(&columns.difference(&hole).unwrap().to_string(), "A:B");
+    }
+
+    #[test]
+    fn union_drops_a_bounded_rect_already_covered_by_an_open_range() {
+        let column = A1::from_str("A:A").unwrap();
+        let subset = A1::from_str("A1:A5").unwrap();
+
+        // A1:A5 is already entirely inside A:A, so the union must collapse to the single open
+        // range rather than keeping both pieces (which would make `iter()` visit A1:A5 twice).
+        assert_eq!(&column.union(&subset).unwrap().to_string(), "A:A");
+    }
+
+    #[test]
+    fn crop_bounds_an_open_column_range() {
+        let col_a = A1::from_str("A:A").unwrap();
+
+        assert_eq!(&col_a.crop((10, 20)).unwrap().to_string(), "A1:A20");
+    }
+
+    #[test]
+    fn crop_outside_the_sheet_is_none() {
+        let b2 = A1::from_str("B2").unwrap();
+
+        assert_eq!(b2.crop((1, 1)), None);
+    }
+
+    #[test]
+    fn zip_with_pairs_a_range_with_a_flat_buffer() {
+        let range = A1::from_str("B2:C3").unwrap();
+        let data = vec!["a0", "a1", "a2", "b0", "b1", "b2", "c0", "c1", "c2"];
+
+        assert_eq!(
+            range.zip_with(&data, 3).map(|(a, v)| (a.to_string(), *v)).collect::<Vec<_>>(),
+            vec![
+                ("B2".to_string(), "b1"),
+                ("C2".to_string(), "b2"),
+                ("B3".to_string(), "c1"),
+                ("C3".to_string(), "c2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn zip_with_skips_addresses_outside_the_buffer() {
+        let column = A1::from_str("A:A").unwrap();
+        let data = vec!["a0", "a1"];
+
+        assert_eq!(
+            column.zip_with(&data, 1).map(|(a, v)| (a.to_string(), *v)).collect::<Vec<_>>(),
+            vec![("A1".to_string(), "a0"), ("A2".to_string(), "a1")]
+        );
+    }
+}
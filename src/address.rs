@@ -0,0 +1,97 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Column, Error, Index, Result, Row};
+
+/// A single cell address, e.g. `C3`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Address {
+    pub column: Column,
+    pub row: Row,
+}
+
+impl Address {
+    /// Build a (non-absolute) `Address` at the given zero-based `(x, y)` coordinates.
+    pub fn new(x: Index, y: Index) -> Self {
+        Self { column: Column::new(x), row: Row::new(y) }
+    }
+
+    pub(crate) fn shift_right(&self, delta: Index) -> Self {
+        Self { column: self.column.shift_right(delta), row: self.row }
+    }
+
+    pub(crate) fn shift_left(&self, delta: Index) -> Self {
+        Self { column: self.column.shift_left(delta), row: self.row }
+    }
+
+    pub(crate) fn shift_down(&self, delta: Index) -> Self {
+        Self { column: self.column, row: self.row.shift_down(delta) }
+    }
+
+    pub(crate) fn shift_up(&self, delta: Index) -> Self {
+        Self { column: self.column, row: self.row.shift_up(delta) }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.column, self.row)
+    }
+}
+
+impl FromStr for Address {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars().peekable();
+
+        let col_absolute = chars.next_if_eq(&'$').is_some();
+        let mut letters = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_alphabetic() {
+                letters.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let row_absolute = chars.next_if_eq(&'$').is_some();
+        let mut digits = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_ascii_digit() {
+                digits.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+
+        if letters.is_empty() || digits.is_empty() || chars.next().is_some() {
+            return Err(Error::InvalidReference(s.to_string()));
+        }
+
+        let column = format!("{}{letters}", if col_absolute { "$" } else { "" }).parse::<Column>()?;
+        let row = format!("{}{digits}", if row_absolute { "$" } else { "" }).parse::<Row>()?;
+
+        Ok(Self { column, row })
+    }
+}
+
+impl AsRef<Column> for Address {
+    fn as_ref(&self) -> &Column {
+        &self.column
+    }
+}
+
+impl AsRef<Row> for Address {
+    fn as_ref(&self) -> &Row {
+        &self.row
+    }
+}
+
+impl From<(Index, Index)> for Address {
+    fn from((x, y): (Index, Index)) -> Self {
+        Self::new(x, y)
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Error, Index, Result, ALPHA};
+
+/// A single column reference, e.g. the `C` in `C3` or the `C` in `C:C`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Column {
+    /// Whether this column is anchored with a `$` (e.g. `$C`)
+    pub absolute: bool,
+
+    /// The zero-based column index
+    pub x: Index,
+}
+
+impl Column {
+    /// Build a (non-absolute) `Column` at the given zero-based index.
+    pub fn new(x: Index) -> Self {
+        Self { absolute: false, x }
+    }
+
+    pub(crate) fn shift_right(&self, delta: Index) -> Self {
+        Self { x: self.x + delta, ..*self }
+    }
+
+    pub(crate) fn shift_left(&self, delta: Index) -> Self {
+        Self { x: self.x.saturating_sub(delta), ..*self }
+    }
+
+    fn to_letters(self) -> String {
+        let mut n = self.x + 1;
+        let mut letters = vec![];
+        while n > 0 {
+            let rem = (n - 1) % 26;
+            letters.push(ALPHA[rem]);
+            n = (n - 1) / 26;
+        }
+        letters.iter().rev().collect()
+    }
+}
+
+impl fmt::Display for Column {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.absolute {
+            write!(f, "$")?;
+        }
+        write!(f, "{}", self.to_letters())
+    }
+}
+
+impl FromStr for Column {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (absolute, letters) = match s.strip_prefix('$') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        Ok(Self { absolute, x: letters_to_index(letters)? })
+    }
+}
+
+impl From<Index> for Column {
+    fn from(x: Index) -> Self {
+        Self::new(x)
+    }
+}
+
+pub(crate) fn letters_to_index(letters: &str) -> Result<Index> {
+    if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(Error::InvalidReference(letters.to_string()));
+    }
+
+    let mut n: usize = 0;
+    for c in letters.chars() {
+        n = n * 26 + (c.to_ascii_uppercase() as usize - 'A' as usize + 1);
+    }
+
+    Ok(n - 1)
+}
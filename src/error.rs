@@ -0,0 +1,18 @@
+use std::fmt;
+
+/// Errors that can occur when parsing an A1-style string.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Error {
+    /// The given string could not be parsed as a valid A1 reference.
+    InvalidReference(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidReference(s) => write!(f, "invalid A1 reference: {s}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
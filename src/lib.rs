@@ -181,6 +181,95 @@
 //! | `"1:1,3:3,8:8"` | Rows 1, 3, and 8          |
 //! | `"A:A,C:C,F:F"` | Columns A, C, and F       |
 //!
+//! ## Multi-area references
+//!
+//! A comma-separated reference parses into a `RangeOrCell::MultiArea`, which contains a cell if
+//! any of its sub-areas does and iterates across all of its sub-areas in turn:
+//!
+//! ```
+//! # use a1::*;
+//! let multi = a1::new("C5:D9,G9:H16").unwrap();
+//! assert_eq!(&multi.to_string(), "C5:D9,G9:H16");
+//!
+//! let g10 = a1::new("G10").unwrap();
+//! assert!(multi.contains(&g10));
+//!
+//! let b2 = a1::new("B2").unwrap();
+//! assert!(!multi.contains(&b2));
+//! ```
+//!
+//! ## Set operations
+//!
+//! `A1` references can be intersected, unioned and subtracted from one another.  Each returns
+//! `None` when the result would be empty, and otherwise normalizes to merged, maximal
+//! rectangles (a single `A1` when possible, a multi-area `A1` otherwise):
+//!
+//! ```
+//! # use a1::*;
+//! let range = a1::new("B2:D10").unwrap();
+//! let hole = a1::new("C5:C6").unwrap();
+//!
+//! assert_eq!(&range.intersect(&hole).unwrap().to_string(), "C5:C6");
+//! assert_eq!(&range.union(&hole).unwrap().to_string(), "B2:D10");
+//! assert_eq!(
+//!     &range.difference(&hole).unwrap().to_string(),
+//!     "B2:B10,C2:C4,C7:C10,D2:D10");
+//!
+//! // non-overlapping references don't intersect:
+//! let a = a1::new("A1:B2").unwrap();
+//! let b = a1::new("C3:D4").unwrap();
+//! assert_eq!(a.intersect(&b), None);
+//! ```
+//!
+//! ## R1C1 notation
+//!
+//! Excel-style R1C1 references can be parsed and rendered too.  An absolute `$`-anchored
+//! `Column`/`Row` becomes a bare `R{n}C{m}`; a non-absolute one becomes a bracketed offset
+//! relative to a supplied anchor cell:
+//!
+//! ```
+//! # use a1::*;
+//! let anchor = Address::new(1, 2); // B3
+//!
+//! let b4 = a1::new("B4").unwrap();
+//! assert_eq!(&b4.to_r1c1(anchor), "R[1]C[0]");
+//!
+//! let absolute = a1::new("$B$4").unwrap();
+//! assert_eq!(&absolute.to_r1c1(anchor), "R4C2");
+//!
+//! assert_eq!(&A1::from_r1c1("R[1]C[0]", anchor).unwrap().to_string(), "B4");
+//! assert_eq!(&A1::from_r1c1("R4C2", anchor).unwrap().to_string(), "$B$4");
+//! ```
+//!
+//! ## Dimensions and cropping
+//!
+//! A bounded reference has a concrete `(width, height)`; an open-ended `ColumnRange`/`RowRange`
+//! can be clamped to a concrete sheet size with `crop`:
+//!
+//! ```
+//! # use a1::*;
+//! let range = a1::new("B2:D10").unwrap();
+//! assert_eq!(range.reference.dimensions(), Some((3, 9)));
+//!
+//! let col_a = a1::new("A:A").unwrap();
+//! assert_eq!(col_a.reference.dimensions(), None);
+//! assert_eq!(&col_a.crop((10, 20)).unwrap().to_string(), "A1:A20");
+//! ```
+//!
+//! ## Zipping with a data buffer
+//!
+//! `zip_with` pairs each address a reference covers with the matching element of a flat,
+//! row-major data buffer (e.g. the cells of a `Grid`), skipping anything outside the buffer:
+//!
+//! ```
+//! # use a1::*;
+//! let range = a1::new("B2:C3").unwrap();
+//! let data = vec![0, 1, 2, 3, 4, 5, 6, 7, 8]; // a 3-wide grid
+//!
+//! let cells: Vec<_> = range.zip_with(&data, 3).map(|(a, v)| (a.to_string(), *v)).collect();
+//! assert_eq!(cells, vec![("B2".to_string(), 4), ("C2".to_string(), 5), ("B3".to_string(), 7), ("C3".to_string(), 8)]);
+//! ```
+//!
 //
 // TODO:
 //
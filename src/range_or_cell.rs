@@ -0,0 +1,748 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Address, Column, Error, Index, Result, Row};
+
+/// The part of an [`crate::A1`] reference that comes after the (optional) sheet name: a single
+/// cell, a rectangular range, an entire column (or range of columns), an entire row (or range of
+/// rows), or a comma-separated, non-contiguous combination of any of the above.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum RangeOrCell {
+    /// A single cell, e.g. `A1`
+    Cell(Address),
+
+    /// A rectangular range between two cells, e.g. `A1:C10`
+    Range { from: Address, to: Address },
+
+    /// An entire column, or a range of columns, e.g. `A:A` or `A:C`
+    ColumnRange { from: Column, to: Column },
+
+    /// An entire row, or a range of rows, e.g. `1:1` or `1:5`
+    RowRange { from: Row, to: Row },
+
+    /// A non-contiguous, comma-separated combination of the above, e.g. `A1:B5,D1:D5`
+    MultiArea(Vec<RangeOrCell>),
+}
+
+/// A bounding rectangle over zero-based column/row indices.  `None` means "unbounded" in that
+/// dimension, which happens for a `ColumnRange` (unbounded rows) or a `RowRange` (unbounded
+/// columns).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Rect {
+    pub(crate) min_col: Option<Index>,
+    pub(crate) min_row: Option<Index>,
+    pub(crate) max_col: Option<Index>,
+    pub(crate) max_row: Option<Index>,
+}
+
+impl RangeOrCell {
+    pub(crate) fn column<C: Into<Column>>(x: C) -> Self {
+        let x = x.into();
+        Self::ColumnRange { from: x, to: x }
+    }
+
+    pub(crate) fn column_range<C: Into<Column>>(xa: C, xb: C) -> Self {
+        Self::ColumnRange { from: xa.into(), to: xb.into() }
+    }
+
+    pub(crate) fn row<R: Into<Row>>(y: R) -> Self {
+        let y = y.into();
+        Self::RowRange { from: y, to: y }
+    }
+
+    pub(crate) fn row_range<R: Into<Row>>(ya: R, yb: R) -> Self {
+        Self::RowRange { from: ya.into(), to: yb.into() }
+    }
+
+    /// Does this reference contain `other`?  For a [`Self::MultiArea`], this is true if any of
+    /// its sub-areas contains `other`.
+    pub fn contains(&self, other: &Self) -> bool {
+        other
+            .rects()
+            .iter()
+            .all(|o| self.rects().iter().any(|s| rect_contains(s, o)))
+    }
+
+    /// The `(width, height)` span of this reference, or `None` if it's open-ended in either
+    /// dimension (a `ColumnRange`'s row span, a `RowRange`'s column span, or a `MultiArea`, which
+    /// has no single span).
+    pub fn dimensions(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::Cell(_) => Some((1, 1)),
+            Self::Range { from, to } => {
+                Some((from.column.x.abs_diff(to.column.x) + 1, from.row.y.abs_diff(to.row.y) + 1))
+            }
+            Self::ColumnRange { .. } | Self::RowRange { .. } | Self::MultiArea(_) => None,
+        }
+    }
+
+    /// Iterate over the pieces of this reference, in the crate's usual row-major order.  A
+    /// `MultiArea` chains the iterators of each of its sub-areas in turn.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = Self> + '_> {
+        match self {
+            Self::Cell(_) => Box::new(std::iter::once(self.clone())),
+            Self::ColumnRange { from, to } => {
+                let (lo, hi) = (from.x.min(to.x), from.x.max(to.x));
+                Box::new((lo..=hi).map(|x| Self::column(Column::new(x))))
+            }
+            Self::RowRange { from, to } => {
+                let (lo, hi) = (from.y.min(to.y), from.y.max(to.y));
+                Box::new((lo..=hi).map(|y| Self::row(Row::new(y))))
+            }
+            Self::Range { from, to } => {
+                let (col_lo, col_hi) = (from.column.x.min(to.column.x), from.column.x.max(to.column.x));
+                let (row_lo, row_hi) = (from.row.y.min(to.row.y), from.row.y.max(to.row.y));
+                Box::new(
+                    (row_lo..=row_hi)
+                        .flat_map(move |y| (col_lo..=col_hi).map(move |x| Self::Cell(Address::new(x, y)))),
+                )
+            }
+            Self::MultiArea(areas) => Box::new(areas.iter().flat_map(|a| a.iter())),
+        }
+    }
+
+    pub fn shift_right(&self, delta: Index) -> Self {
+        match self {
+            Self::Cell(a) => Self::Cell(a.shift_right(delta)),
+            Self::Range { from, to } => Self::Range { from: from.shift_right(delta), to: to.shift_right(delta) },
+            Self::ColumnRange { from, to } => {
+                Self::ColumnRange { from: from.shift_right(delta), to: to.shift_right(delta) }
+            }
+            Self::RowRange { .. } => self.clone(),
+            Self::MultiArea(areas) => Self::MultiArea(areas.iter().map(|a| a.shift_right(delta)).collect()),
+        }
+    }
+
+    pub fn shift_left(&self, delta: Index) -> Self {
+        match self {
+            Self::Cell(a) => Self::Cell(a.shift_left(delta)),
+            Self::Range { from, to } => Self::Range { from: from.shift_left(delta), to: to.shift_left(delta) },
+            Self::ColumnRange { from, to } => {
+                Self::ColumnRange { from: from.shift_left(delta), to: to.shift_left(delta) }
+            }
+            Self::RowRange { .. } => self.clone(),
+            Self::MultiArea(areas) => Self::MultiArea(areas.iter().map(|a| a.shift_left(delta)).collect()),
+        }
+    }
+
+    pub fn shift_down(&self, delta: Index) -> Self {
+        match self {
+            Self::Cell(a) => Self::Cell(a.shift_down(delta)),
+            Self::Range { from, to } => Self::Range { from: from.shift_down(delta), to: to.shift_down(delta) },
+            Self::RowRange { from, to } => Self::RowRange { from: from.shift_down(delta), to: to.shift_down(delta) },
+            Self::ColumnRange { .. } => self.clone(),
+            Self::MultiArea(areas) => Self::MultiArea(areas.iter().map(|a| a.shift_down(delta)).collect()),
+        }
+    }
+
+    pub fn shift_up(&self, delta: Index) -> Self {
+        match self {
+            Self::Cell(a) => Self::Cell(a.shift_up(delta)),
+            Self::Range { from, to } => Self::Range { from: from.shift_up(delta), to: to.shift_up(delta) },
+            Self::RowRange { from, to } => Self::RowRange { from: from.shift_up(delta), to: to.shift_up(delta) },
+            Self::ColumnRange { .. } => self.clone(),
+            Self::MultiArea(areas) => Self::MultiArea(areas.iter().map(|a| a.shift_up(delta)).collect()),
+        }
+    }
+
+    /// Every cell address covered by this reference, in row-major order, bounding any open
+    /// `ColumnRange`/`RowRange` dimension to `[0, width)`/`[0, height)`.  Used by
+    /// [`crate::A1::zip_with`] to pair addresses with a flat data buffer.
+    pub(crate) fn cells(&self, width: Index, height: Index) -> Box<dyn Iterator<Item = Address>> {
+        Box::new(self.rects().into_iter().flat_map(move |rect| {
+            let min_col = rect.min_col.unwrap_or(0);
+            let end_col = rect.max_col.map_or(width, |c| c + 1);
+            let min_row = rect.min_row.unwrap_or(0);
+            let end_row = rect.max_row.map_or(height, |r| r + 1);
+
+            (min_row..end_row).flat_map(move |y| (min_col..end_col).map(move |x| Address::new(x, y)))
+        }))
+    }
+
+    /// The bounding rectangle(s) of this reference.  Every variant except `MultiArea` is itself
+    /// a single rectangle; a `MultiArea` is flattened into the rectangles of its sub-areas.
+    pub(crate) fn rects(&self) -> Vec<Rect> {
+        match self {
+            Self::MultiArea(areas) => areas.iter().flat_map(|a| a.rects()).collect(),
+            _ => vec![self.rect()],
+        }
+    }
+
+    fn rect(&self) -> Rect {
+        match self {
+            Self::Cell(a) => Rect {
+                min_col: Some(a.column.x),
+                max_col: Some(a.column.x),
+                min_row: Some(a.row.y),
+                max_row: Some(a.row.y),
+            },
+            Self::Range { from, to } => Rect {
+                min_col: Some(from.column.x.min(to.column.x)),
+                max_col: Some(from.column.x.max(to.column.x)),
+                min_row: Some(from.row.y.min(to.row.y)),
+                max_row: Some(from.row.y.max(to.row.y)),
+            },
+            Self::ColumnRange { from, to } => Rect {
+                min_col: Some(from.x.min(to.x)),
+                max_col: Some(from.x.max(to.x)),
+                min_row: None,
+                max_row: None,
+            },
+            Self::RowRange { from, to } => Rect {
+                min_col: None,
+                max_col: None,
+                min_row: Some(from.y.min(to.y)),
+                max_row: Some(from.y.max(to.y)),
+            },
+            Self::MultiArea(_) => unreachable!("MultiArea has no single bounding rect"),
+        }
+    }
+}
+
+/// Normalize a (possibly empty, possibly overlapping) collection of rectangles into maximal
+/// rectangles covering the same area.  Column-only and row-only rects
+/// (unbounded in the other dimension) are merged along their one bounded axis; fully-bounded
+/// rects already subsumed by one of those whole-column/whole-row strips are dropped entirely
+/// (they contribute no area a caller hasn't already seen); the rest are merged with a vertical
+/// sweep: gather every distinct column boundary into strips, record each strip's covered
+/// row-intervals, then coalesce horizontally-adjacent strips that share identical row-interval
+/// sets into maximal rectangles.  The bounded pieces never overlap one another, but they are not
+/// always the fewest possible (`A1:C1` plus `B1:B3` comes out as `A1`, `B1:B3`, `C1`), and
+/// whole-column/whole-row strips can overlap each other or a bounded rect they only partly cover.
+pub(crate) fn coalesce_rects(rects: &[Rect]) -> Vec<Rect> {
+    let mut column_only = vec![];
+    let mut row_only = vec![];
+    let mut bounded = vec![];
+
+    for r in rects {
+        match (r.min_col, r.max_col, r.min_row, r.max_row) {
+            (Some(c0), Some(c1), None, None) => column_only.push((c0, c1)),
+            (None, None, Some(r0), Some(r1)) => row_only.push((r0, r1)),
+            (Some(c0), Some(c1), Some(r0), Some(r1)) => bounded.push((c0, c1, r0, r1)),
+            _ => {}
+        }
+    }
+
+    let column_only = merge_intervals(column_only);
+    let row_only = merge_intervals(row_only);
+
+    // A bounded rect already fully covered by a whole-column or whole-row strip contributes no
+    // area of its own; drop it so `iter()`/`zip_with` don't visit the same cells twice.
+    bounded.retain(|&(c0, c1, r0, r1)| {
+        !column_only.iter().any(|&(lo, hi)| lo <= c0 && c1 <= hi) && !row_only.iter().any(|&(lo, hi)| lo <= r0 && r1 <= hi)
+    });
+
+    let mut out = vec![];
+
+    for (lo, hi) in column_only {
+        out.push(Rect { min_col: Some(lo), max_col: Some(hi), min_row: None, max_row: None });
+    }
+
+    for (lo, hi) in row_only {
+        out.push(Rect { min_col: None, max_col: None, min_row: Some(lo), max_row: Some(hi) });
+    }
+
+    out.extend(coalesce_bounded(bounded));
+
+    out
+}
+
+/// Merge overlapping or adjacent `[lo, hi]` intervals into their minimal covering set.
+fn merge_intervals(mut intervals: Vec<(Index, Index)>) -> Vec<(Index, Index)> {
+    intervals.sort_unstable();
+
+    let mut merged: Vec<(Index, Index)> = vec![];
+    for (lo, hi) in intervals {
+        match merged.last_mut() {
+            Some((_, last_hi)) if lo <= *last_hi + 1 => *last_hi = (*last_hi).max(hi),
+            _ => merged.push((lo, hi)),
+        }
+    }
+    merged
+}
+
+/// The vertical-sweep half of [`coalesce_rects`], over fully-bounded `(min_col, max_col, min_row,
+/// max_row)` rectangles.
+fn coalesce_bounded(bounded: Vec<(Index, Index, Index, Index)>) -> Vec<Rect> {
+    type ColumnStrip = ((Index, Index), Vec<(Index, Index)>);
+
+    if bounded.is_empty() {
+        return vec![];
+    }
+
+    let mut boundaries: Vec<Index> = bounded
+        .iter()
+        .flat_map(|&(c0, c1, _, _)| [c0, c1 + 1])
+        .collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    // one (column-range, row-interval-set) entry per strip between consecutive boundaries
+    let mut strips: Vec<ColumnStrip> = vec![];
+    for window in boundaries.windows(2) {
+        let (strip_lo, strip_hi) = (window[0], window[1] - 1);
+
+        let rows = merge_intervals(
+            bounded
+                .iter()
+                .filter(|&&(c0, c1, _, _)| c0 <= strip_lo && strip_hi <= c1)
+                .map(|&(_, _, r0, r1)| (r0, r1))
+                .collect(),
+        );
+
+        strips.push(((strip_lo, strip_hi), rows));
+    }
+
+    // coalesce horizontally-adjacent strips that share an identical row-interval set
+    let mut groups: Vec<ColumnStrip> = vec![];
+    for ((lo, hi), rows) in strips {
+        match groups.last_mut() {
+            Some((col_range, last_rows)) if *last_rows == rows => col_range.1 = hi,
+            _ => groups.push(((lo, hi), rows)),
+        }
+    }
+
+    groups
+        .into_iter()
+        .flat_map(|((col_lo, col_hi), rows)| {
+            rows.into_iter().map(move |(row_lo, row_hi)| Rect {
+                min_col: Some(col_lo),
+                max_col: Some(col_hi),
+                min_row: Some(row_lo),
+                max_row: Some(row_hi),
+            })
+        })
+        .collect()
+}
+
+fn opt_max(a: Option<Index>, b: Option<Index>) -> Option<Index> {
+    match (a, b) {
+        (None, None) => None,
+        (None, Some(x)) | (Some(x), None) => Some(x),
+        (Some(a), Some(b)) => Some(a.max(b)),
+    }
+}
+
+fn opt_min(a: Option<Index>, b: Option<Index>) -> Option<Index> {
+    match (a, b) {
+        (None, None) => None,
+        (None, Some(x)) | (Some(x), None) => Some(x),
+        (Some(a), Some(b)) => Some(a.min(b)),
+    }
+}
+
+/// The overlap of two rectangles, or `None` if they don't overlap.
+pub(crate) fn intersect_rect(a: &Rect, b: &Rect) -> Option<Rect> {
+    let rect = Rect {
+        min_col: opt_max(a.min_col, b.min_col),
+        min_row: opt_max(a.min_row, b.min_row),
+        max_col: opt_min(a.max_col, b.max_col),
+        max_row: opt_min(a.max_row, b.max_row),
+    };
+
+    let empty = matches!((rect.min_col, rect.max_col), (Some(lo), Some(hi)) if lo > hi)
+        || matches!((rect.min_row, rect.max_row), (Some(lo), Some(hi)) if lo > hi);
+
+    if empty {
+        None
+    } else {
+        Some(rect)
+    }
+}
+
+/// `a` with the overlap (if any) of `b` removed, as disjoint rectangles.
+///
+/// The top/bottom strips span `a`'s own column range and cut `a`'s own row bound before/after
+/// the overlap; the left/right strips cut `a`'s own column bound before/after the overlap the
+/// same way and span the overlap's row range, using `a`'s own (possibly open) row bound instead wherever
+/// `a`'s rows are unbounded — `b` never touched those rows to begin with, so clipping to the
+/// overlap's row range there would silently drop everything outside it.
+///
+/// When `a` is open on an axis (a `ColumnRange`'s rows, a `RowRange`'s columns), the before/after
+/// cut on *that* axis can't be computed at all — there's no `[a_bound, overlap_bound]` span when
+/// `a_bound` doesn't exist (the crate has no syntax for "every row below 5"). Rather than silently
+/// dropping that band (which would misrepresent a real, mostly-untouched remainder as fully
+/// covered), the unrepresentable band is kept whole, open on that axis, as a crop-able
+/// approximation: [`crate::A1::crop`] to a concrete sheet size first for an exact result.
+pub(crate) fn rect_minus(a: &Rect, b: &Rect) -> Vec<Rect> {
+    let overlap = match intersect_rect(a, b) {
+        Some(overlap) => overlap,
+        None => return vec![*a],
+    };
+
+    // Rows to attach to the left/right (column) strips: the overlap's row span wherever `a` is
+    // itself bounded there (the top/bottom strips already account for the rest), but `a`'s own
+    // (possibly open) row span when `a`'s rows are unbounded, since nothing else covers them.
+    let (side_min_row, side_max_row) =
+        if a.min_row.is_none() { (a.min_row, a.max_row) } else { (overlap.min_row, overlap.max_row) };
+
+    let mut out = vec![];
+
+    if a.min_row.is_some() {
+        if let Some((lo, hi)) = bound_before(a.min_row, overlap.min_row) {
+            out.push(Rect { min_col: a.min_col, max_col: a.max_col, min_row: Some(lo), max_row: Some(hi) });
+        }
+
+        if let Some((lo, hi)) = bound_after(a.max_row, overlap.max_row) {
+            out.push(Rect { min_col: a.min_col, max_col: a.max_col, min_row: Some(lo), max_row: Some(hi) });
+        }
+    } else if overlap.min_row.is_some() {
+        // `a`'s rows are open and `b` only cuts a bounded slice out of them, so the untouched
+        // remainder is infinite in both directions and can't be cut to a finite span. Keep the
+        // overlap's own column band whole (approximate) instead of dropping it outright.
+        out.push(Rect { min_col: overlap.min_col, max_col: overlap.max_col, min_row: None, max_row: None });
+    }
+
+    if a.min_col.is_some() {
+        if let Some((lo, hi)) = bound_before(a.min_col, overlap.min_col) {
+            out.push(Rect { min_col: Some(lo), max_col: Some(hi), min_row: side_min_row, max_row: side_max_row });
+        }
+
+        if let Some((lo, hi)) = bound_after(a.max_col, overlap.max_col) {
+            out.push(Rect { min_col: Some(lo), max_col: Some(hi), min_row: side_min_row, max_row: side_max_row });
+        }
+    } else if overlap.min_col.is_some() {
+        // Same reasoning, mirrored for a `RowRange`'s open columns.
+        out.push(Rect { min_col: None, max_col: None, min_row: overlap.min_row, max_row: overlap.max_row });
+    }
+
+    out
+}
+
+/// The `[a_bound, overlap_bound - 1]` span before the overlap starts, if `a` extends that far.
+fn bound_before(a_bound: Option<Index>, overlap_bound: Option<Index>) -> Option<(Index, Index)> {
+    match (a_bound, overlap_bound) {
+        (Some(a), Some(ov)) if ov > a => Some((a, ov - 1)),
+        _ => None,
+    }
+}
+
+/// The `[overlap_bound + 1, a_bound]` span after the overlap ends, if `a` extends that far.
+fn bound_after(a_bound: Option<Index>, overlap_bound: Option<Index>) -> Option<(Index, Index)> {
+    match (a_bound, overlap_bound) {
+        (Some(a), Some(ov)) if ov < a => Some((ov + 1, a)),
+        _ => None,
+    }
+}
+
+/// Convert a normalized rectangle back into the most specific `RangeOrCell` that represents it.
+pub(crate) fn rect_to_reference(rect: &Rect) -> RangeOrCell {
+    match (rect.min_col, rect.max_col, rect.min_row, rect.max_row) {
+        (Some(c0), Some(c1), Some(r0), Some(r1)) if c0 == c1 && r0 == r1 => {
+            RangeOrCell::Cell(Address::new(c0, r0))
+        }
+        (Some(c0), Some(c1), Some(r0), Some(r1)) => {
+            RangeOrCell::Range { from: Address::new(c0, r0), to: Address::new(c1, r1) }
+        }
+        (Some(c0), Some(c1), None, None) => RangeOrCell::ColumnRange { from: Column::new(c0), to: Column::new(c1) },
+        (None, None, Some(r0), Some(r1)) => RangeOrCell::RowRange { from: Row::new(r0), to: Row::new(r1) },
+        _ => unreachable!("coalesce_rects never produces a half-open rectangle"),
+    }
+}
+
+/// Build the normalized `RangeOrCell` for a set of rectangles: `None` if empty, a single variant
+/// if there's only one piece left after coalescing, otherwise a `MultiArea`.
+pub(crate) fn rects_to_reference(rects: Vec<Rect>) -> Option<RangeOrCell> {
+    let mut pieces: Vec<RangeOrCell> = coalesce_rects(&rects).iter().map(rect_to_reference).collect();
+
+    match pieces.len() {
+        0 => None,
+        1 => Some(pieces.remove(0)),
+        _ => Some(RangeOrCell::MultiArea(pieces)),
+    }
+}
+
+fn rect_contains(container: &Rect, inner: &Rect) -> bool {
+    fn axis_ok(container_min: Option<Index>, container_max: Option<Index>, inner_min: Option<Index>, inner_max: Option<Index>) -> bool {
+        match (container_min, container_max) {
+            (None, None) => true,
+            _ => match (inner_min, inner_max) {
+                (Some(imin), Some(imax)) => {
+                    container_min.is_none_or(|c| imin >= c) && container_max.is_none_or(|c| imax <= c)
+                }
+                _ => false,
+            },
+        }
+    }
+
+    axis_ok(container.min_col, container.max_col, inner.min_col, inner.max_col)
+        && axis_ok(container.min_row, container.max_row, inner.min_row, inner.max_row)
+}
+
+impl fmt::Display for RangeOrCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cell(a) => write!(f, "{a}"),
+            Self::Range { from, to } => write!(f, "{from}:{to}"),
+            Self::ColumnRange { from, to } => write!(f, "{from}:{to}"),
+            Self::RowRange { from, to } => write!(f, "{from}:{to}"),
+            Self::MultiArea(areas) => {
+                let joined = areas.iter().map(|a| a.to_string()).collect::<Vec<_>>().join(",");
+                write!(f, "{joined}")
+            }
+        }
+    }
+}
+
+impl FromStr for RangeOrCell {
+    type Err = Error;
+
+    /// Parse a single piece of a reference (no sheet name, no commas).  Use [`crate::A1::from_str`]
+    /// to parse a full reference, which handles splitting a multi-area selection on commas.
+    fn from_str(s: &str) -> Result<Self> {
+        if let Some((lhs, rhs)) = s.split_once(':') {
+            if is_column_str(lhs) && is_column_str(rhs) {
+                return Ok(Self::ColumnRange { from: lhs.parse()?, to: rhs.parse()? });
+            }
+
+            if is_row_str(lhs) && is_row_str(rhs) {
+                return Ok(Self::RowRange { from: lhs.parse()?, to: rhs.parse()? });
+            }
+
+            return Ok(Self::Range { from: lhs.parse()?, to: rhs.parse()? });
+        }
+
+        Ok(Self::Cell(s.parse()?))
+    }
+}
+
+impl From<Column> for RangeOrCell {
+    fn from(column: Column) -> Self {
+        Self::column(column)
+    }
+}
+
+impl From<Row> for RangeOrCell {
+    fn from(row: Row) -> Self {
+        Self::row(row)
+    }
+}
+
+impl From<Address> for RangeOrCell {
+    fn from(address: Address) -> Self {
+        Self::Cell(address)
+    }
+}
+
+fn is_column_str(s: &str) -> bool {
+    let s = s.strip_prefix('$').unwrap_or(s);
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn is_row_str(s: &str) -> bool {
+    let s = s.strip_prefix('$').unwrap_or(s);
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+impl RangeOrCell {
+    /// Render this reference in R1C1 notation, e.g. `R3C2` or `R[1]C[-2]`.  An absolute
+    /// `Column`/`Row` (the `$` form in A1 notation) is rendered as a bare `R{n}C{m}`; a
+    /// non-absolute one is rendered relative to `anchor` as `R[dy]C[dx]`.  `ColumnRange` and
+    /// `RowRange` always render as the bare `C{n}`/`R{n}` forms.
+    pub(crate) fn to_r1c1(&self, anchor: Address) -> String {
+        match self {
+            Self::Cell(a) => address_to_r1c1(a, anchor),
+            Self::Range { from, to } => format!("{}:{}", address_to_r1c1(from, anchor), address_to_r1c1(to, anchor)),
+            Self::ColumnRange { from, to } if from == to => format!("C{}", from.x + 1),
+            Self::ColumnRange { from, to } => format!("C{}:C{}", from.x + 1, to.x + 1),
+            Self::RowRange { from, to } if from == to => format!("R{}", from.y + 1),
+            Self::RowRange { from, to } => format!("R{}:R{}", from.y + 1, to.y + 1),
+            Self::MultiArea(areas) => {
+                areas.iter().map(|a| a.to_r1c1(anchor)).collect::<Vec<_>>().join(",")
+            }
+        }
+    }
+
+    /// Parse a single R1C1 piece (no sheet name, no commas), relative to `anchor`.  Use
+    /// [`crate::A1::from_r1c1`] to parse a full, possibly multi-area, reference.
+    pub(crate) fn from_r1c1(s: &str, anchor: Address) -> Result<Self> {
+        if let Some((lhs, rhs)) = s.split_once(':') {
+            if is_r1c1_column_str(lhs) && is_r1c1_column_str(rhs) {
+                return Ok(Self::ColumnRange { from: r1c1_column(lhs)?, to: r1c1_column(rhs)? });
+            }
+
+            if is_r1c1_row_str(lhs) && is_r1c1_row_str(rhs) {
+                return Ok(Self::RowRange { from: r1c1_row(lhs)?, to: r1c1_row(rhs)? });
+            }
+
+            return Ok(Self::Range { from: address_from_r1c1(lhs, anchor)?, to: address_from_r1c1(rhs, anchor)? });
+        }
+
+        if is_r1c1_column_str(s) {
+            return Ok(Self::column(r1c1_column(s)?));
+        }
+
+        if is_r1c1_row_str(s) {
+            return Ok(Self::row(r1c1_row(s)?));
+        }
+
+        Ok(Self::Cell(address_from_r1c1(s, anchor)?))
+    }
+}
+
+fn address_to_r1c1(address: &Address, anchor: Address) -> String {
+    let row = if address.row.absolute {
+        format!("R{}", address.row.y + 1)
+    } else {
+        format!("R[{}]", address.row.y as isize - anchor.row.y as isize)
+    };
+
+    let column = if address.column.absolute {
+        format!("C{}", address.column.x + 1)
+    } else {
+        format!("C[{}]", address.column.x as isize - anchor.column.x as isize)
+    };
+
+    format!("{row}{column}")
+}
+
+fn address_from_r1c1(s: &str, anchor: Address) -> Result<Address> {
+    let rest = s.strip_prefix('R').ok_or_else(|| Error::InvalidReference(s.to_string()))?;
+    let (y, row_absolute, rest) = r1c1_component(rest, anchor.row.y, s)?;
+
+    let rest = rest.strip_prefix('C').ok_or_else(|| Error::InvalidReference(s.to_string()))?;
+    let (x, col_absolute, rest) = r1c1_component(rest, anchor.column.x, s)?;
+
+    if !rest.is_empty() {
+        return Err(Error::InvalidReference(s.to_string()));
+    }
+
+    Ok(Address { column: Column { absolute: col_absolute, x }, row: Row { absolute: row_absolute, y } })
+}
+
+/// Parse a single `R`/`C` component: either a bracketed, anchor-relative offset (`[dy]`) or a
+/// bare, absolute one-based number. Returns the parsed zero-based index, whether it was absolute,
+/// and whatever of `s` is left unconsumed.
+fn r1c1_component<'a>(s: &'a str, anchor: Index, whole: &str) -> Result<(Index, bool, &'a str)> {
+    if let Some(rest) = s.strip_prefix('[') {
+        let end = rest.find(']').ok_or_else(|| Error::InvalidReference(whole.to_string()))?;
+        let delta: isize = rest[..end].parse().map_err(|_| Error::InvalidReference(whole.to_string()))?;
+        let value = anchor as isize + delta;
+
+        if value < 0 {
+            return Err(Error::InvalidReference(whole.to_string()));
+        }
+
+        Ok((value as Index, false, &rest[end + 1..]))
+    } else {
+        let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+
+        if end == 0 {
+            return Err(Error::InvalidReference(whole.to_string()));
+        }
+
+        let n: Index = s[..end].parse().map_err(|_| Error::InvalidReference(whole.to_string()))?;
+
+        if n == 0 {
+            return Err(Error::InvalidReference(whole.to_string()));
+        }
+
+        Ok((n - 1, true, &s[end..]))
+    }
+}
+
+fn is_r1c1_column_str(s: &str) -> bool {
+    s.strip_prefix('C').is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn is_r1c1_row_str(s: &str) -> bool {
+    s.strip_prefix('R').is_some_and(|rest| !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn r1c1_column(s: &str) -> Result<Column> {
+    let n: Index = s[1..].parse().map_err(|_| Error::InvalidReference(s.to_string()))?;
+
+    if n == 0 {
+        return Err(Error::InvalidReference(s.to_string()));
+    }
+
+    Ok(Column::new(n - 1))
+}
+
+fn r1c1_row(s: &str) -> Result<Row> {
+    let n: Index = s[1..].parse().map_err(|_| Error::InvalidReference(s.to_string()))?;
+
+    if n == 0 {
+        return Err(Error::InvalidReference(s.to_string()));
+    }
+
+    Ok(Row::new(n - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::A1;
+
+    #[test]
+    fn multi_area_parse_and_display() {
+        let parsed = A1::from_str("C5:D9,G9:H16").unwrap();
+
+        assert_eq!(
+            parsed.reference,
+            RangeOrCell::MultiArea(vec![
+                RangeOrCell::Range { from: Address::new(2, 4), to: Address::new(3, 8) },
+                RangeOrCell::Range { from: Address::new(6, 8), to: Address::new(7, 15) },
+            ])
+        );
+        assert_eq!(&parsed.to_string(), "C5:D9,G9:H16");
+    }
+
+    #[test]
+    fn multi_area_contains() {
+        let multi = RangeOrCell::MultiArea(vec![RangeOrCell::column(Column::new(0)), RangeOrCell::row(Row::new(2))]);
+
+        assert!(multi.contains(&RangeOrCell::Cell(Address::new(0, 10))));
+        assert!(multi.contains(&RangeOrCell::Cell(Address::new(5, 2))));
+        assert!(!multi.contains(&RangeOrCell::Cell(Address::new(1, 1))));
+    }
+
+    #[test]
+    fn multi_area_iter_chains_sub_areas() {
+        let multi = A1::from_str("A:A,1:1").unwrap();
+
+        assert_eq!(
+            multi.iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+            vec!["A:A", "1:1"]
+        );
+    }
+
+    #[test]
+    fn r1c1_relative_and_absolute_cells() {
+        let anchor = Address::new(2, 2); // C3
+
+        assert_eq!(&A1::from_str("B4").unwrap().to_r1c1(anchor), "R[1]C[-1]");
+        assert_eq!(&A1::from_str("$B$4").unwrap().to_r1c1(anchor), "R4C2");
+    }
+
+    #[test]
+    fn r1c1_round_trips_a_range() {
+        let anchor = Address::new(2, 2); // C3
+
+        let range = A1::from_str("B2:D10").unwrap();
+        let r1c1 = range.to_r1c1(anchor);
+
+        assert_eq!(A1::from_r1c1(&r1c1, anchor).unwrap().to_string(), range.to_string());
+    }
+
+    #[test]
+    fn dimensions_of_bounded_and_open_references() {
+        assert_eq!(RangeOrCell::Cell(Address::new(0, 0)).dimensions(), Some((1, 1)));
+        assert_eq!(
+            RangeOrCell::Range { from: Address::new(1, 1), to: Address::new(3, 9) }.dimensions(),
+            Some((3, 9))
+        );
+        assert_eq!(RangeOrCell::column(Column::new(0)).dimensions(), None);
+        assert_eq!(RangeOrCell::row(Row::new(0)).dimensions(), None);
+    }
+
+    #[test]
+    fn r1c1_column_and_row_only() {
+        let anchor = Address::new(2, 2);
+
+        assert_eq!(&A1::from_str("A:C").unwrap().to_r1c1(anchor), "C1:C3");
+        assert_eq!(&A1::from_str("3:3").unwrap().to_r1c1(anchor), "R3");
+        assert_eq!(A1::from_r1c1("C1:C3", anchor).unwrap().to_string(), "A:C");
+        assert_eq!(A1::from_r1c1("R3", anchor).unwrap().to_string(), "3:3");
+    }
+}
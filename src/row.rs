@@ -0,0 +1,65 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{Error, Index, Result};
+
+/// A single row reference, e.g. the `3` in `B3` or the `3` in `3:3`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Row {
+    /// Whether this row is anchored with a `$` (e.g. `$3`)
+    pub absolute: bool,
+
+    /// The zero-based row index
+    pub y: Index,
+}
+
+impl Row {
+    /// Build a (non-absolute) `Row` at the given zero-based index.
+    pub fn new(y: Index) -> Self {
+        Self { absolute: false, y }
+    }
+
+    pub(crate) fn shift_down(&self, delta: Index) -> Self {
+        Self { y: self.y + delta, ..*self }
+    }
+
+    pub(crate) fn shift_up(&self, delta: Index) -> Self {
+        Self { y: self.y.saturating_sub(delta), ..*self }
+    }
+}
+
+impl fmt::Display for Row {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.absolute {
+            write!(f, "$")?;
+        }
+        write!(f, "{}", self.y + 1)
+    }
+}
+
+impl FromStr for Row {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (absolute, digits) = match s.strip_prefix('$') {
+            Some(rest) => (true, rest),
+            None => (false, s),
+        };
+
+        let y: Index = digits
+            .parse()
+            .map_err(|_| Error::InvalidReference(s.to_string()))?;
+
+        if y == 0 {
+            return Err(Error::InvalidReference(s.to_string()));
+        }
+
+        Ok(Self { absolute, y: y - 1 })
+    }
+}
+
+impl From<Index> for Row {
+    fn from(y: Index) -> Self {
+        Self::new(y)
+    }
+}